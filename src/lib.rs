@@ -30,13 +30,18 @@ pub struct Turtle {
     x: f32,
     y: f32,
     h: f32,
+    pen: bool,
+    fill_color: (u8, u8, u8),
     ops: Vec<TurtleOp>,
 }
 
 enum TurtleOp {
-    MoveTo(f32, f32),
-    LineTo(f32, f32),
+    MoveTo(f32, f32, f32),
+    LineTo(f32, f32, f32),
     SetColor(u8, u8, u8),
+    SetPenSize(u32),
+    BeginFill,
+    EndFill(u8, u8, u8),
 }
 
 impl Turtle {
@@ -46,16 +51,23 @@ impl Turtle {
             x: 0.0,
             y: 0.0,
             h: 0.0,
+            pen: true,
+            fill_color: (255, 255, 255),
             ops: Vec::new(),
         }
     }
 
-    /// Move forward, drawing a line (backwards if dist < 0)
+    /// Move forward, drawing a line (backwards if dist < 0), or just repositioning if the pen
+    /// is up
     pub fn forward(&mut self, dist: i32) {
         let h_rad = self.h.to_radians();
         self.x += (dist as f32) * f32::cos(h_rad);
         self.y += (dist as f32) * f32::sin(h_rad);
-        self.ops.push(TurtleOp::LineTo(self.x, self.y));
+        if self.pen {
+            self.ops.push(TurtleOp::LineTo(self.x, self.y, self.h));
+        } else {
+            self.ops.push(TurtleOp::MoveTo(self.x, self.y, self.h));
+        }
     }
 
     /// Turn right, or counter-clockwise (left if degrees < 0)
@@ -63,11 +75,52 @@ impl Turtle {
         self.h += degrees;
     }
 
+    /// Lift the pen, so future `forward` calls move without drawing
+    pub fn pen_up(&mut self) {
+        self.pen = false;
+    }
+
+    /// Lower the pen, so future `forward` calls draw again
+    pub fn pen_down(&mut self) {
+        self.pen = true;
+    }
+
+    /// Set the width, in pixels, of future lines (default: 1). The anti-aliased PNG renderer
+    /// ignores this and draws every line 1px wide.
+    pub fn set_pen_size(&mut self, px: u32) {
+        self.ops.push(TurtleOp::SetPenSize(px));
+    }
+
+    /// Trace an arc of the given `radius` sweeping `extent_degrees`, approximated as a
+    /// polyline of short chords (so it renders and animates like any other sequence of
+    /// `forward`/`turn` calls). Ends facing tangent to the arc, as if it had been walked.
+    pub fn circle(&mut self, radius: f32, extent_degrees: f32) {
+        let r = radius.abs();
+        let arc_len = r * extent_degrees.to_radians().abs();
+        let n = (arc_len / 2.0).ceil().max(1.0) as u32;
+        let step = extent_degrees / n as f32;
+        // A negative radius mirrors the arc to the other side of the current heading, i.e. it
+        // reverses the turn direction without changing how far the turtle sweeps.
+        let turn_step = if radius < 0.0 { -step } else { step };
+        let chord = (2.0 * r * (step.to_radians() / 2.0).abs().sin()).round() as i32;
+
+        for _ in 0..n {
+            self.turn(turn_step / 2.0);
+            self.forward(chord);
+            self.turn(turn_step / 2.0);
+        }
+    }
+
+    /// Alias for `circle`, for callers who find "arc" the more natural name for a partial sweep
+    pub fn arc(&mut self, radius: f32, extent_degrees: f32) {
+        self.circle(radius, extent_degrees);
+    }
+
     /// Jump to a new location without drawing a line
     pub fn move_to(&mut self, nx: i32, ny: i32) {
         self.x = nx as f32;
         self.y = ny as f32;
-        self.ops.push(TurtleOp::MoveTo(self.x, self.y));
+        self.ops.push(TurtleOp::MoveTo(self.x, self.y, self.h));
     }
 
     /// Set the color to use for future lines
@@ -75,6 +128,37 @@ impl Turtle {
         self.ops.push(TurtleOp::SetColor(r, g, b));
     }
 
+    /// Set the color to use for future lines, parsed from a `#rgb`/`#rrggbb` hex code or a
+    /// common CSS color name (e.g. `"#fe0000"`, `"white"`)
+    pub fn set_color_str(&mut self, s: &str) -> Result<(), ColorParseError> {
+        let (r, g, b) = parse_color(s)?;
+        self.set_color(r, g, b);
+        Ok(())
+    }
+
+    /// Set the color to use for future fills (see `begin_fill`/`end_fill`)
+    pub fn set_fill_color(&mut self, r: u8, g: u8, b: u8) {
+        self.fill_color = (r, g, b);
+    }
+
+    /// Set the fill color, parsed from a `#rgb`/`#rrggbb` hex code or a common CSS color name
+    pub fn set_fill_color_str(&mut self, s: &str) -> Result<(), ColorParseError> {
+        let (r, g, b) = parse_color(s)?;
+        self.set_fill_color(r, g, b);
+        Ok(())
+    }
+
+    /// Start recording the vertices of a polygon to be filled once `end_fill` is called
+    pub fn begin_fill(&mut self) {
+        self.ops.push(TurtleOp::BeginFill);
+    }
+
+    /// Close and fill the polygon traced since the matching `begin_fill`
+    pub fn end_fill(&mut self) {
+        let (r, g, b) = self.fill_color;
+        self.ops.push(TurtleOp::EndFill(r, g, b));
+    }
+
     /// Get the current position
     pub fn position(&self) -> (f32, f32) {
         (self.x, self.y)
@@ -91,10 +175,43 @@ impl Turtle {
             i: self.ops.iter(),
             x: 0,
             y: 0,
+            heading: 0.0,
             color: (255, 255, 255),
+            width: 1,
         }
     }
 
+    /// Get the polygons filled between each `begin_fill`/`end_fill` pair
+    pub fn fills(&self) -> Vec<Fill> {
+        let mut out = Vec::new();
+        let mut x = 0i32;
+        let mut y = 0i32;
+        let mut open: Option<Vec<(i32, i32)>> = None;
+
+        for op in self.ops.iter() {
+            match *op {
+                TurtleOp::MoveTo(tx, ty, _) | TurtleOp::LineTo(tx, ty, _) => {
+                    x = tx as i32;
+                    y = ty as i32;
+                    if let Some(ref mut verts) = open {
+                        verts.push((x, y));
+                    }
+                }
+                TurtleOp::SetColor(..) | TurtleOp::SetPenSize(..) => {}
+                TurtleOp::BeginFill => {
+                    open = Some(vec![(x, y)]);
+                }
+                TurtleOp::EndFill(r, g, b) => {
+                    if let Some(vertices) = open.take() {
+                        out.push(Fill { vertices: vertices, color: (r, g, b) });
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
     /// Get a builder for rendering to a PNG
     pub fn draw_png(&self) -> PngTurtle {
         PngTurtle::new(&self)
@@ -104,6 +221,99 @@ impl Turtle {
     pub fn draw_sdl(&self) -> SdlTurtle {
         SdlTurtle::new(&self)
     }
+
+    /// Get a builder for rendering to an animated GIF
+    pub fn draw_gif(&self) -> GifTurtle {
+        GifTurtle::new(&self)
+    }
+}
+
+/// A filled polygon, with the vertices walked between `begin_fill` and `end_fill`
+pub struct Fill {
+    pub vertices: Vec<(i32, i32)>,
+    pub color: (u8, u8, u8),
+}
+
+/// Error returned when `set_color_str`/`set_fill_color_str` can't parse their argument
+#[derive(Debug)]
+pub struct ColorParseError(String);
+
+impl std::fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "'{}' is not a valid hex code or color name", self.0)
+    }
+}
+
+impl std::error::Error for ColorParseError {
+    fn description(&self) -> &str {
+        "invalid color string"
+    }
+}
+
+fn parse_color(s: &str) -> Result<(u8, u8, u8), ColorParseError> {
+    if let Some(hex) = s.strip_prefix('#') {
+        parse_hex(hex).ok_or_else(|| ColorParseError(s.to_string()))
+    } else {
+        named_color(s).ok_or_else(|| ColorParseError(s.to_string()))
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<(u8, u8, u8)> {
+    if !hex.is_ascii() {
+        return None;
+    }
+
+    match hex.len() {
+        3 => {
+            let r = u8::from_str_radix(&hex[0..1], 16).ok()?;
+            let g = u8::from_str_radix(&hex[1..2], 16).ok()?;
+            let b = u8::from_str_radix(&hex[2..3], 16).ok()?;
+            Some((r * 17, g * 17, b * 17))
+        }
+        6 => {
+            let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+            let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+            let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+            Some((r, g, b))
+        }
+        _ => None,
+    }
+}
+
+fn named_color(s: &str) -> Option<(u8, u8, u8)> {
+    match s.to_lowercase().as_str() {
+        "black" => Some((0, 0, 0)),
+        "white" => Some((255, 255, 255)),
+        "red" => Some((255, 0, 0)),
+        "green" => Some((0, 128, 0)),
+        "blue" => Some((0, 0, 255)),
+        "yellow" => Some((255, 255, 0)),
+        "cyan" | "aqua" => Some((0, 255, 255)),
+        "magenta" | "fuchsia" => Some((255, 0, 255)),
+        "gray" | "grey" => Some((128, 128, 128)),
+        "orange" => Some((255, 165, 0)),
+        "purple" => Some((128, 0, 128)),
+        "pink" => Some((255, 192, 203)),
+        "brown" => Some((165, 42, 42)),
+        "navy" => Some((0, 0, 128)),
+        "teal" => Some((0, 128, 128)),
+        "maroon" => Some((128, 0, 0)),
+        "olive" => Some((128, 128, 0)),
+        "lime" => Some((0, 255, 0)),
+        "silver" => Some((192, 192, 192)),
+        "gold" => Some((255, 215, 0)),
+        "indigo" => Some((75, 0, 130)),
+        "violet" => Some((238, 130, 238)),
+        "turquoise" => Some((64, 224, 208)),
+        "coral" => Some((255, 127, 80)),
+        "salmon" => Some((250, 128, 114)),
+        "khaki" => Some((240, 230, 140)),
+        "chocolate" => Some((210, 105, 30)),
+        "crimson" => Some((220, 20, 60)),
+        "beige" => Some((245, 245, 220)),
+        "ivory" => Some((255, 255, 240)),
+        _ => None,
+    }
 }
 
 /// Iterator over the lines walked by a Turtle
@@ -111,14 +321,19 @@ pub struct Lines<'a> {
     i: std::slice::Iter<'a, TurtleOp>,
     x: i32,
     y: i32,
+    heading: f32,
     color: (u8, u8, u8),
+    width: u32,
 }
 
-/// A line, with associated color
+/// A line, with associated color, pen width, and the turtle's heading at the end of it
+#[derive(Clone, Copy)]
 pub struct Line {
     pub start: (i32, i32),
     pub end: (i32, i32),
     pub color: (u8, u8, u8),
+    pub width: u32,
+    pub heading: f32,
 }
 
 impl<'a> Iterator for Lines<'a> {
@@ -127,26 +342,34 @@ impl<'a> Iterator for Lines<'a> {
     fn next(&mut self) -> Option<Line> {
         loop {
             match self.i.next() {
-                Some(&TurtleOp::MoveTo(tx, ty)) => {
+                Some(&TurtleOp::MoveTo(tx, ty, th)) => {
                     self.x = tx as i32;
                     self.y = ty as i32;
+                    self.heading = th;
                 }
-                Some(&TurtleOp::LineTo(tx, ty)) => {
+                Some(&TurtleOp::LineTo(tx, ty, th)) => {
                     let lastx = self.x;
                     let lasty = self.y;
 
                     self.x = tx as i32;
                     self.y = ty as i32;
+                    self.heading = th;
 
                     return Some(Line {
                         start: (lastx, lasty),
                         end: (self.x, self.y),
                         color: self.color,
+                        width: self.width,
+                        heading: self.heading,
                     });
                 }
                 Some(&TurtleOp::SetColor(r, g, b)) => {
                     self.color = (r, g, b);
                 }
+                Some(&TurtleOp::SetPenSize(px)) => {
+                    self.width = px;
+                }
+                Some(&TurtleOp::BeginFill) | Some(&TurtleOp::EndFill(..)) => {}
                 None => {
                     return None;
                 }
@@ -158,7 +381,7 @@ impl<'a> Iterator for Lines<'a> {
 /// Builder object for rendering to a PNG
 pub struct PngTurtle<'a> {
     size: (u32, u32),
-    antialias: bool,    // TODO: implement anti-aliased line drawing
+    antialias: bool,
     bg: (u8, u8, u8),
     turtle: &'a Turtle,
 }
@@ -195,8 +418,15 @@ impl<'a> PngTurtle<'a> {
     pub fn save(&'a self, fname: &str) {
         let bgpix = Rgb::from_channels(self.bg.0, self.bg.1, self.bg.2, 0);
         let mut img = RgbImage::from_pixel(self.size.0, self.size.1, bgpix);
+        for fill in self.turtle.fills() {
+            fill_polygon_img(&mut img, &fill);
+        }
         for line in self.turtle.lines() {
-            draw_line_img(&mut img, line)
+            if self.antialias {
+                draw_line_img_aa(&mut img, line)
+            } else {
+                draw_line_img(&mut img, line)
+            }
         }
 
         let ref mut fout = File::create(fname).unwrap();
@@ -204,6 +434,54 @@ impl<'a> PngTurtle<'a> {
     }
 }
 
+// Sorted x-coordinates where the polygon's edges cross scanline `y`, using the standard
+// half-open rule (`y >= min(y0,y1) && y < max(y0,y1)`) so shared vertices aren't counted twice.
+fn polygon_crossings(verts: &[(i32, i32)], y: i32) -> Vec<i32> {
+    let mut xs: Vec<i32> = Vec::new();
+    let n = verts.len();
+    for i in 0..n {
+        let (x0, y0) = verts[i];
+        let (x1, y1) = verts[(i + 1) % n];
+        if y0 == y1 {
+            continue;
+        }
+        if y >= i32::min(y0, y1) && y < i32::max(y0, y1) {
+            let t = (y - y0) as f32 / (y1 - y0) as f32;
+            xs.push((x0 as f32 + t * (x1 - x0) as f32).round() as i32);
+        }
+    }
+    xs.sort();
+    xs
+}
+
+// Scanline polygon fill: for each row, find where the polygon's edges cross it, then
+// fill the spans between consecutive crossings.
+fn fill_polygon_img(img: &mut RgbImage, fill: &Fill) {
+    let verts = &fill.vertices;
+    if verts.len() < 3 {
+        return;
+    }
+
+    let w = img.width() as i32;
+    let h = img.height() as i32;
+    let ymin = verts.iter().map(|v| v.1).min().unwrap().max(0);
+    let ymax = verts.iter().map(|v| v.1).max().unwrap().min(h - 1);
+    let px = Rgb::from_channels(fill.color.0, fill.color.1, fill.color.2, 0);
+
+    for y in ymin..=ymax {
+        let xs = polygon_crossings(verts, y);
+        let mut i = 0;
+        while i + 1 < xs.len() {
+            let xstart = i32::max(xs[i], 0);
+            let xend = i32::min(xs[i + 1], w - 1);
+            for x in xstart..=xend {
+                img.put_pixel(x as u32, y as u32, px);
+            }
+            i += 2;
+        }
+    }
+}
+
 fn draw_line_img(img: &mut RgbImage, line: Line) {
     let w = img.width();
     let h = img.height();
@@ -218,8 +496,6 @@ fn draw_line_img(img: &mut RgbImage, line: Line) {
     let sy = if y0 < y1 { 1 } else { -1 };
     let mut err = dx + dy;
 
-    let px = Rgb::from_channels(line.color.0, line.color.1, line.color.2, 0);
-
     let mut x = x0;
     let mut y = y0;
 
@@ -228,7 +504,7 @@ fn draw_line_img(img: &mut RgbImage, line: Line) {
             break;
         }
 
-        img.put_pixel(x as u32, y as u32, px);
+        stamp_img(img, x, y, line.color, line.width);
 
         if x == x1 && y == y1 {
             break;
@@ -246,6 +522,220 @@ fn draw_line_img(img: &mut RgbImage, line: Line) {
     }
 }
 
+// Plot a pixel, or for pen widths greater than 1px, a filled disc of that diameter centered
+// on it, so thicker pens don't just draw a 1px-wide line with gaps at corners.
+fn stamp_img(img: &mut RgbImage, cx: i32, cy: i32, color: (u8, u8, u8), width: u32) {
+    let w = img.width() as i32;
+    let h = img.height() as i32;
+    let px = Rgb::from_channels(color.0, color.1, color.2, 0);
+
+    if width <= 1 {
+        if cx >= 0 && cy >= 0 && cx < w && cy < h {
+            img.put_pixel(cx as u32, cy as u32, px);
+        }
+        return;
+    }
+
+    let r = (width / 2) as i32;
+    for dy in -r..=r {
+        for dx in -r..=r {
+            if dx * dx + dy * dy > r * r {
+                continue;
+            }
+            let (x, y) = (cx + dx, cy + dy);
+            if x >= 0 && y >= 0 && x < w && y < h {
+                img.put_pixel(x as u32, y as u32, px);
+            }
+        }
+    }
+}
+
+fn fpart(x: f32) -> f32 {
+    x - x.floor()
+}
+
+fn blend_channel(bg: u8, fg: u8, a: f32) -> u8 {
+    (bg as f32 * (1.0 - a) + fg as f32 * a).round() as u8
+}
+
+// Plot a pixel blended against whatever is already in the image, so overlapping
+// anti-aliased lines don't just clobber each other.
+fn blend_pixel(img: &mut RgbImage, x: i32, y: i32, color: (u8, u8, u8), a: f32) {
+    let w = img.width();
+    let h = img.height();
+    if x < 0 || y < 0 || x >= w as i32 || y >= h as i32 {
+        return;
+    }
+
+    let a = a.clamp(0.0, 1.0);
+    let bg = img.get_pixel(x as u32, y as u32).channels().to_owned();
+    let out = Rgb::from_channels(
+        blend_channel(bg[0], color.0, a),
+        blend_channel(bg[1], color.1, a),
+        blend_channel(bg[2], color.2, a),
+        0,
+    );
+    img.put_pixel(x as u32, y as u32, out);
+}
+
+// Xiaolin Wu's anti-aliased line algorithm: each integer step along the major
+// axis plots two adjacent pixels, weighted by how far the true line passes
+// between them.
+fn draw_line_img_aa(img: &mut RgbImage, line: Line) {
+    let (mut x0, mut y0) = (line.start.0 as f32, line.start.1 as f32);
+    let (mut x1, mut y1) = (line.end.0 as f32, line.end.1 as f32);
+    let color = line.color;
+
+    let steep = (y1 - y0).abs() > (x1 - x0).abs();
+    if steep {
+        std::mem::swap(&mut x0, &mut y0);
+        std::mem::swap(&mut x1, &mut y1);
+    }
+    if x0 > x1 {
+        std::mem::swap(&mut x0, &mut x1);
+        std::mem::swap(&mut y0, &mut y1);
+    }
+
+    let dx = x1 - x0;
+    let dy = y1 - y0;
+    let gradient = if dx == 0.0 { 1.0 } else { dy / dx };
+
+    let plot = |img: &mut RgbImage, x: i32, y: i32, a: f32| {
+        if steep {
+            blend_pixel(img, y, x, color, a);
+        } else {
+            blend_pixel(img, x, y, color, a);
+        }
+    };
+
+    // first endpoint
+    let xend = x0.round();
+    let yend = y0 + gradient * (xend - x0);
+    let xgap = 1.0 - fpart(x0 + 0.5);
+    let xpxl1 = xend as i32;
+    let ypxl1 = yend.floor() as i32;
+    plot(img, xpxl1, ypxl1, (1.0 - fpart(yend)) * xgap);
+    plot(img, xpxl1, ypxl1 + 1, fpart(yend) * xgap);
+    let mut intery = yend + gradient;
+
+    // second endpoint
+    let xend = x1.round();
+    let yend = y1 + gradient * (xend - x1);
+    let xgap = fpart(x1 + 0.5);
+    let xpxl2 = xend as i32;
+    let ypxl2 = yend.floor() as i32;
+    plot(img, xpxl2, ypxl2, (1.0 - fpart(yend)) * xgap);
+    plot(img, xpxl2, ypxl2 + 1, fpart(yend) * xgap);
+
+    for x in (xpxl1 + 1)..xpxl2 {
+        let y = intery.floor() as i32;
+        plot(img, x, y, 1.0 - fpart(intery));
+        plot(img, x, y + 1, fpart(intery));
+        intery += gradient;
+    }
+}
+
+/// Builder object for rendering to an animated GIF
+///
+/// Renders the same progressive reveal that `draw_sdl` animates interactively, but to a GIF
+/// file that can be shared without running SDL.
+pub struct GifTurtle<'a> {
+    size: (u32, u32),
+    bg: (u8, u8, u8),
+    lines_per_frame: u32,
+    frame_delay: u16,
+    hold_final_frame: bool,
+    turtle: &'a Turtle,
+}
+
+impl<'a> GifTurtle<'a> {
+    fn new(turtle: &Turtle) -> GifTurtle {
+        GifTurtle {
+            size: (500, 500),
+            bg: (0, 0, 0),
+            lines_per_frame: 1,
+            frame_delay: 5,
+            hold_final_frame: true,
+            turtle: turtle,
+        }
+    }
+
+    /// Set the size in pixels of the image to be written (default: 500x500)
+    pub fn size(&'a mut self, width: u32, height: u32) -> &mut GifTurtle {
+        self.size = (width, height);
+        self
+    }
+
+    /// Set the image's background color (default: #000)
+    pub fn background(&'a mut self, r: u8, g: u8, b: u8) -> &mut GifTurtle {
+        self.bg = (r, g, b);
+        self
+    }
+
+    /// Set how many lines are drawn between each emitted frame (default: 1). Raise this to
+    /// keep the file size down for drawings with many short lines.
+    pub fn lines_per_frame(&'a mut self, n: u32) -> &mut GifTurtle {
+        self.lines_per_frame = u32::max(n, 1);
+        self
+    }
+
+    /// Set the delay between frames, in hundredths of a second (default: 5, i.e. 20fps)
+    pub fn frame_delay(&'a mut self, hundredths: u16) -> &mut GifTurtle {
+        self.frame_delay = hundredths;
+        self
+    }
+
+    /// Set whether the last frame is held once the drawing completes (default: true)
+    pub fn hold_final_frame(&'a mut self, hold: bool) -> &mut GifTurtle {
+        self.hold_final_frame = hold;
+        self
+    }
+
+    /// Save the animation as a GIF with the given filename
+    pub fn save(&'a self, fname: &str) {
+        let bgpix = Rgb::from_channels(self.bg.0, self.bg.1, self.bg.2, 0);
+        let mut img = RgbImage::from_pixel(self.size.0, self.size.1, bgpix);
+        let fills = self.turtle.fills();
+        for fill in &fills {
+            fill_polygon_img(&mut img, fill);
+        }
+
+        let ref mut fout = File::create(fname).unwrap();
+        let mut encoder = image::gif::Encoder::new(fout);
+
+        let mut pending = 0;
+        let mut any_lines = false;
+        let mut frames_emitted = 0;
+        for line in self.turtle.lines() {
+            any_lines = true;
+            draw_line_img(&mut img, line);
+            pending += 1;
+
+            if pending >= self.lines_per_frame {
+                pending = 0;
+                encode_gif_frame(&mut encoder, &img, self.frame_delay);
+                frames_emitted += 1;
+            }
+        }
+
+        // A fill built purely from move_to/pen-up positioning (no LineTo events at all) still
+        // needs its frame emitted, or the GIF would come out empty while the PNG renders fine.
+        let any_content = any_lines || !fills.is_empty();
+        if any_content && (frames_emitted == 0 || pending > 0) {
+            encode_gif_frame(&mut encoder, &img, self.frame_delay);
+        }
+        if any_content && self.hold_final_frame {
+            encode_gif_frame(&mut encoder, &img, self.frame_delay);
+        }
+    }
+}
+
+fn encode_gif_frame<W: std::io::Write>(encoder: &mut image::gif::Encoder<W>, img: &RgbImage, delay: u16) {
+    let mut frame = image::gif::Frame::from_rgb(img.width() as u16, img.height() as u16, &mut img.clone().into_raw());
+    frame.delay = delay;
+    encoder.encode_frame(frame).unwrap();
+}
+
 /// Builder for displaying with SDL
 ///
 /// Creates a window and displays an animated form of the turtle's path. If running in interactive
@@ -262,6 +752,7 @@ pub struct SdlTurtle<'a> {
     interactive: bool,
     speed: f32,
     bg: (u8, u8, u8),
+    cursor: bool,
     turtle: &'a Turtle,
 }
 
@@ -273,6 +764,7 @@ impl<'a> SdlTurtle<'a> {
             interactive: true,
             speed: 60.0,
             bg: (0, 0, 0),
+            cursor: false,
             turtle: turtle,
         }
     }
@@ -307,6 +799,13 @@ impl<'a> SdlTurtle<'a> {
         self
     }
 
+    /// Set whether a small triangle showing the turtle's position and heading is drawn on top
+    /// of the path as it animates (default: false)
+    pub fn show_cursor(&'a mut self, show: bool) -> &mut SdlTurtle {
+        self.cursor = show;
+        self
+    }
+
     /// Create an SDL window and begin running through the turtle's drawing
     pub fn show(&self) {
         let sdl_context = sdl2::init().unwrap();
@@ -322,6 +821,28 @@ impl<'a> SdlTurtle<'a> {
 
         renderer.set_draw_color(bgcolor);
         renderer.clear();
+        draw_fills_sdl(&mut renderer, &self.turtle.fills());
+
+        // When the cursor overlay is on, the path so far is accumulated into this offscreen
+        // texture instead of being re-stroked every frame: each step draws only the one new
+        // line into it, and the main loop just blits the whole thing back each frame before
+        // drawing the cursor triangle on top. Without this, redrawing the full history behind
+        // the cursor every frame would make animating a long path (e.g. an L-system fractal)
+        // quadratically slower as it progresses.
+        let mut path_texture = if self.cursor {
+            let mut texture = renderer.create_texture_target(None, self.size.0, self.size.1).unwrap();
+            {
+                let mut target = renderer.render_target().unwrap();
+                target.set(&mut texture).unwrap();
+                renderer.set_draw_color(bgcolor);
+                renderer.clear();
+                draw_fills_sdl(&mut renderer, &self.turtle.fills());
+                renderer.render_target().unwrap().reset().unwrap();
+            }
+            Some(texture)
+        } else {
+            None
+        };
 
         let mut paused = false;
         let mut step = false;
@@ -334,9 +855,20 @@ impl<'a> SdlTurtle<'a> {
             if !paused || step {
                 step = false;
                 if let Some(line) = line_iter.next() {
-                    renderer.set_draw_color(Color::RGB(line.color.0, line.color.1, line.color.2));
-                    renderer.draw_line(Point::new(line.start.0, line.start.1),
-                                       Point::new(line.end.0, line.end.1)).unwrap();
+                    if let Some(ref mut texture) = path_texture {
+                        let mut target = renderer.render_target().unwrap();
+                        target.set(texture).unwrap();
+                        renderer.set_draw_color(Color::RGB(line.color.0, line.color.1, line.color.2));
+                        draw_line_sdl(&mut renderer, &line);
+                        renderer.render_target().unwrap().reset().unwrap();
+
+                        renderer.copy(&*texture, None, None).unwrap();
+                        draw_cursor_sdl(&mut renderer, line.end, line.heading);
+                    } else {
+                        renderer.set_draw_color(Color::RGB(line.color.0, line.color.1, line.color.2));
+                        draw_line_sdl(&mut renderer, &line);
+                    }
+
                     renderer.present();
                 } else {
                     paused = true;
@@ -362,6 +894,15 @@ impl<'a> SdlTurtle<'a> {
                                 line_iter = self.turtle.lines();
                                 renderer.set_draw_color(bgcolor);
                                 renderer.clear();
+                                draw_fills_sdl(&mut renderer, &self.turtle.fills());
+                                if let Some(ref mut texture) = path_texture {
+                                    let mut target = renderer.render_target().unwrap();
+                                    target.set(texture).unwrap();
+                                    renderer.set_draw_color(bgcolor);
+                                    renderer.clear();
+                                    draw_fills_sdl(&mut renderer, &self.turtle.fills());
+                                    renderer.render_target().unwrap().reset().unwrap();
+                                }
                             }
                             Keycode::S => {
                                 step = true;
@@ -385,3 +926,75 @@ impl<'a> SdlTurtle<'a> {
         }
     }
 }
+
+// Draws a line at its pen width by stamping `width` parallel lines offset along the normal,
+// mirroring the filled-disc approach used for the PNG backend.
+fn draw_line_sdl(renderer: &mut sdl2::render::Renderer, line: &Line) {
+    if line.width <= 1 {
+        renderer.draw_line(Point::new(line.start.0, line.start.1),
+                           Point::new(line.end.0, line.end.1)).unwrap();
+        return;
+    }
+
+    let dx = (line.end.0 - line.start.0) as f32;
+    let dy = (line.end.1 - line.start.1) as f32;
+    let len = (dx * dx + dy * dy).sqrt();
+    let (nx, ny) = if len > 0.0 { (-dy / len, dx / len) } else { (0.0, 0.0) };
+
+    let half = line.width as f32 / 2.0;
+    for i in 0..line.width {
+        let t = i as f32 - half + 0.5;
+        let ox = (nx * t).round() as i32;
+        let oy = (ny * t).round() as i32;
+        renderer.draw_line(Point::new(line.start.0 + ox, line.start.1 + oy),
+                           Point::new(line.end.0 + ox, line.end.1 + oy)).unwrap();
+    }
+}
+
+// Draws a small directional triangle at `pos`, pointing along `heading_deg`, so users can see
+// where the turtle is and which way it's facing as the path animates.
+fn draw_cursor_sdl(renderer: &mut sdl2::render::Renderer, pos: (i32, i32), heading_deg: f32) {
+    const SIZE: f32 = 10.0;
+    const BACK_ANGLE: f32 = 150.0;
+
+    let h = heading_deg.to_radians();
+    let back = BACK_ANGLE.to_radians();
+
+    let point_at = |angle: f32| {
+        Point::new((pos.0 as f32 + SIZE * angle.cos()).round() as i32,
+                   (pos.1 as f32 + SIZE * angle.sin()).round() as i32)
+    };
+
+    let tip = point_at(h);
+    let left = point_at(h + back);
+    let right = point_at(h - back);
+
+    renderer.set_draw_color(Color::RGB(255, 255, 255));
+    renderer.draw_line(tip, left).unwrap();
+    renderer.draw_line(left, right).unwrap();
+    renderer.draw_line(right, tip).unwrap();
+}
+
+// Draws all filled polygons up front, using the same scanline spans as the PNG backend, so
+// the animated lines that follow are overlaid on top of their fills rather than under them.
+fn draw_fills_sdl(renderer: &mut sdl2::render::Renderer, fills: &[Fill]) {
+    for fill in fills {
+        let verts = &fill.vertices;
+        if verts.len() < 3 {
+            continue;
+        }
+
+        let ymin = verts.iter().map(|v| v.1).min().unwrap();
+        let ymax = verts.iter().map(|v| v.1).max().unwrap();
+
+        renderer.set_draw_color(Color::RGB(fill.color.0, fill.color.1, fill.color.2));
+        for y in ymin..=ymax {
+            let xs = polygon_crossings(verts, y);
+            let mut i = 0;
+            while i + 1 < xs.len() {
+                renderer.draw_line(Point::new(xs[i], y), Point::new(xs[i + 1], y)).unwrap();
+                i += 2;
+            }
+        }
+    }
+}